@@ -100,47 +100,108 @@ impl Attribute {
     /// Parses the tokens after the path as a [`Meta`](enum.Meta.html) if
     /// possible.
     pub fn interpret_meta(&self) -> Option<Meta> {
-        let name = if self.path.segments.len() == 1 {
-            &self.path.segments.first().unwrap().value().ident
-        } else {
-            return None;
-        };
+        let path = self.path.clone();
 
         if self.tts.is_empty() {
-            return Some(Meta::Word(*name));
+            return Some(Meta::Word(path));
         }
 
         let tts = self.tts.clone().into_iter().collect::<Vec<_>>();
 
         if tts.len() == 1 {
-            if let TokenNode::Group(Delimiter::Parenthesis, ref ts) = tts[0].kind {
+            let delimited = match tts[0].kind {
+                TokenNode::Group(Delimiter::Parenthesis, ref ts) => {
+                    Some((MetaListDelim::Paren(token::Paren(tts[0].span)), ts))
+                }
+                TokenNode::Group(Delimiter::Bracket, ref ts) => {
+                    Some((MetaListDelim::Bracket(token::Bracket(tts[0].span)), ts))
+                }
+                TokenNode::Group(Delimiter::Brace, ref ts) => {
+                    Some((MetaListDelim::Brace(token::Brace(tts[0].span)), ts))
+                }
+                _ => None,
+            };
+            if let Some((delimiter, ts)) = delimited {
                 let tokens = ts.clone().into_iter().collect::<Vec<_>>();
                 if let Some(nested_meta_items) = list_of_nested_meta_items_from_tokens(&tokens) {
                     return Some(Meta::List(MetaList {
-                        paren_token: token::Paren(tts[0].span),
-                        ident: *name,
+                        path: path,
+                        delimiter: delimiter,
                         nested: nested_meta_items,
                     }));
                 }
             }
         }
 
-        if tts.len() == 2 {
-            if let TokenNode::Op('=', Spacing::Alone) = tts[0].kind {
+        if let TokenNode::Op('=', Spacing::Alone) = tts[0].kind {
+            if tts.len() == 2 {
                 if let TokenNode::Literal(ref lit) = tts[1].kind {
                     return Some(Meta::NameValue(MetaNameValue {
-                        ident: *name,
+                        path: path,
                         eq_token: Token![=]([tts[0].span]),
                         lit: Lit::new(lit.clone(), tts[1].span),
                     }));
                 }
             }
+
+            return Some(Meta::Verbatim(MetaVerbatim {
+                path: path,
+                eq_token: Token![=]([tts[0].span]),
+                tts: tts[1..].iter().cloned().collect(),
+            }));
         }
 
         None
     }
 }
 
+/// Parses a possibly multi-segment, colon-delimited path like `a::b::c` off
+/// the front of `tts`, mirroring the subset of path grammar that shows up in
+/// attribute position (no leading `::`, no generic arguments).
+fn path_from_tokens(tts: &[TokenTree]) -> Option<(Path, &[TokenTree])> {
+    let mut segments = Punctuated::new();
+
+    let (sym, mut rest) = match tts.first()?.kind {
+        TokenNode::Term(sym) => (sym, &tts[1..]),
+        _ => return None,
+    };
+    segments.push_value(PathSegment::from(Ident::new(sym.as_str(), tts[0].span)));
+
+    loop {
+        if rest.len() < 3 {
+            break;
+        }
+        let double_colon = match (&rest[0].kind, &rest[1].kind) {
+            (&TokenNode::Op(':', Spacing::Joint), &TokenNode::Op(':', Spacing::Alone)) => true,
+            _ => false,
+        };
+        if !double_colon {
+            break;
+        }
+        let sym = match rest[2].kind {
+            TokenNode::Term(sym) => sym,
+            _ => break,
+        };
+        segments.push_punct(Token![::]([rest[0].span, rest[1].span]));
+        segments.push_value(PathSegment::from(Ident::new(sym.as_str(), rest[2].span)));
+        rest = &rest[3..];
+    }
+
+    Some((
+        Path {
+            leading_colon: None,
+            segments: segments,
+        },
+        rest,
+    ))
+}
+
+/// Returns true if `path` consists of the single segment `ident`, e.g. for
+/// recognizing the desugared `#[doc = "..."]` form of a doc comment.
+fn path_is_ident(path: &Path, ident: &str) -> bool {
+    path.segments.len() == 1 && path.segments.first().unwrap().value().ident == ident
+}
+
 fn nested_meta_item_from_tokens(tts: &[TokenTree]) -> Option<(NestedMeta, &[TokenTree])> {
     assert!(!tts.is_empty());
 
@@ -150,32 +211,73 @@ fn nested_meta_item_from_tokens(tts: &[TokenTree]) -> Option<(NestedMeta, &[Toke
             Some((NestedMeta::Literal(lit), &tts[1..]))
         }
 
-        TokenNode::Term(sym) => {
-            let ident = Ident::new(sym.as_str(), tts[0].span);
-            if tts.len() >= 3 {
-                if let TokenNode::Op('=', Spacing::Alone) = tts[1].kind {
-                    if let TokenNode::Literal(ref lit) = tts[2].kind {
-                        let pair = MetaNameValue {
-                            ident: Ident::new(sym.as_str(), tts[0].span),
-                            eq_token: Token![=]([tts[1].span]),
-                            lit: Lit::new(lit.clone(), tts[2].span),
-                        };
-                        return Some((Meta::NameValue(pair).into(), &tts[3..]));
+        TokenNode::Term(_) => {
+            let (path, rest) = path_from_tokens(tts)?;
+
+            if !rest.is_empty() {
+                if let TokenNode::Op('=', Spacing::Alone) = rest[0].kind {
+                    if rest.len() >= 2 {
+                        if let TokenNode::Literal(ref lit) = rest[1].kind {
+                            let pair = MetaNameValue {
+                                path: path,
+                                eq_token: Token![=]([rest[0].span]),
+                                lit: Lit::new(lit.clone(), rest[1].span),
+                            };
+                            return Some((Meta::NameValue(pair).into(), &rest[2..]));
+                        }
                     }
+
+                    // The right-hand side is not a literal, e.g. the `x < 5`
+                    // in a hypothetical `#[cfg_attr(foo, precondition = x <
+                    // 5)]`. Capture the tokens after `=` up to the next
+                    // top-level comma (or the end of the list) verbatim so
+                    // the caller can reparse them.
+                    let eq_token = Token![=]([rest[0].span]);
+                    let value_len = rest[1..]
+                        .iter()
+                        .position(|tt| match tt.kind {
+                            TokenNode::Op(',', Spacing::Alone) => true,
+                            _ => false,
+                        })
+                        .unwrap_or(rest.len() - 1);
+                    let consumed = 1 + value_len;
+                    let verbatim_tts = rest[1..consumed].iter().cloned().collect::<TokenStream>();
+                    return Some((
+                        Meta::Verbatim(MetaVerbatim {
+                            path: path,
+                            eq_token: eq_token,
+                            tts: verbatim_tts,
+                        })
+                        .into(),
+                        &rest[consumed..],
+                    ));
                 }
             }
 
-            if tts.len() >= 2 {
-                if let TokenNode::Group(Delimiter::Parenthesis, ref inner_tts) = tts[1].kind {
-                    let inner_tts = inner_tts.clone().into_iter().collect::<Vec<_>>();
+            if let Some(tt) = rest.first() {
+                let delimited = match tt.kind {
+                    TokenNode::Group(Delimiter::Parenthesis, ref ts) => {
+                        Some((MetaListDelim::Paren(token::Paren(tt.span)), ts))
+                    }
+                    TokenNode::Group(Delimiter::Bracket, ref ts) => {
+                        Some((MetaListDelim::Bracket(token::Bracket(tt.span)), ts))
+                    }
+                    TokenNode::Group(Delimiter::Brace, ref ts) => {
+                        Some((MetaListDelim::Brace(token::Brace(tt.span)), ts))
+                    }
+                    _ => None,
+                };
+
+                if let Some((delimiter, ts)) = delimited {
+                    let inner_tts = ts.clone().into_iter().collect::<Vec<_>>();
                     return match list_of_nested_meta_items_from_tokens(&inner_tts) {
                         Some(nested_meta_items) => {
                             let list = MetaList {
-                                ident: ident,
-                                paren_token: token::Paren(tts[1].span),
+                                path: path,
+                                delimiter: delimiter,
                                 nested: nested_meta_items,
                             };
-                            Some((Meta::List(list).into(), &tts[2..]))
+                            Some((Meta::List(list).into(), &rest[1..]))
                         }
 
                         None => None,
@@ -183,7 +285,7 @@ fn nested_meta_item_from_tokens(tts: &[TokenTree]) -> Option<(NestedMeta, &[Toke
                 }
             }
 
-            Some((Meta::Word(ident).into(), &tts[1..]))
+            Some((Meta::Word(path).into(), rest))
         }
 
         _ => None,
@@ -249,6 +351,22 @@ ast_enum! {
     }
 }
 
+ast_enum! {
+    /// The delimiter used to surround the arguments of a [`MetaList`], e.g.
+    /// the choice of `(...)`, `[...]`, or `{...}` in `#[derive(Copy)]`.
+    ///
+    /// *This type is available if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    ///
+    /// [`MetaList`]: struct.MetaList.html
+    #[cfg_attr(feature = "clone-impls", derive(Copy))]
+    pub enum MetaListDelim {
+        Paren(token::Paren),
+        Bracket(token::Bracket),
+        Brace(token::Brace),
+    }
+}
+
 ast_enum_of_structs! {
     /// Content of a compile-time structured attribute.
     ///
@@ -274,14 +392,16 @@ ast_enum_of_structs! {
     ///
     /// [syntax tree enum]: enum.Expr.html#syntax-tree-enums
     pub enum Meta {
-        pub Word(Ident),
+        /// A meta word is like the `test` in `#[test]`, or the
+        /// `rustfmt::skip` in `#[rustfmt::skip]`.
+        pub Word(Path),
         /// A structured list within an attribute, like `derive(Copy, Clone)`.
         ///
         /// *This type is available if Syn is built with the `"derive"` or
         /// `"full"` feature.*
         pub List(MetaList {
-            pub ident: Ident,
-            pub paren_token: token::Paren,
+            pub path: Path,
+            pub delimiter: MetaListDelim,
             pub nested: Punctuated<NestedMeta, Token![,]>,
         }),
         /// A name-value pair within an attribute, like `feature = "nightly"`.
@@ -289,25 +409,77 @@ ast_enum_of_structs! {
         /// *This type is available if Syn is built with the `"derive"` or
         /// `"full"` feature.*
         pub NameValue(MetaNameValue {
-            pub ident: Ident,
+            pub path: Path,
             pub eq_token: Token![=],
             pub lit: Lit,
         }),
+        /// A name-value-shaped meta item whose right-hand side is not a
+        /// literal, like the `x < 5` in a hypothetical
+        /// `#[precondition = x < 5]`.
+        pub Verbatim(MetaVerbatim),
+    }
+}
+
+ast_struct! {
+    /// A name-value-shaped meta item whose right-hand side is an arbitrary
+    /// token sequence rather than a [`Lit`](enum.Lit.html), as produced by
+    /// [`Meta::Verbatim`](enum.Meta.html#variant.Verbatim).
+    pub struct MetaVerbatim #manual_extra_traits {
+        pub path: Path,
+        pub eq_token: Token![=],
+        pub tts: TokenStream,
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+impl Eq for MetaVerbatim {}
+
+#[cfg(feature = "extra-traits")]
+impl PartialEq for MetaVerbatim {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.eq_token == other.eq_token
+            && TokenStreamHelper(&self.tts) == TokenStreamHelper(&other.tts)
+    }
+}
+
+#[cfg(feature = "extra-traits")]
+impl Hash for MetaVerbatim {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.path.hash(state);
+        self.eq_token.hash(state);
+        TokenStreamHelper(&self.tts).hash(state);
     }
 }
 
 impl Meta {
-    /// Returns the identifier that begins this structured meta item.
+    /// Returns the path that begins this structured meta item.
     ///
-    /// For example this would return the `test` in `#[test]`, the `derive` in
-    /// `#[derive(Copy)]`, and the `path` in `#[path = "sys/windows.rs"]`.
-    pub fn name(&self) -> Ident {
+    /// For example this would return the `test` in `#[test]`, the `derive`
+    /// in `#[derive(Copy)]`, and the `crate::precondition` in
+    /// `#[crate::precondition]`.
+    pub fn path(&self) -> &Path {
         match *self {
-            Meta::Word(ref meta) => *meta,
-            Meta::List(ref meta) => meta.ident,
-            Meta::NameValue(ref meta) => meta.ident,
+            Meta::Word(ref path) => path,
+            Meta::List(ref meta) => &meta.path,
+            Meta::NameValue(ref meta) => &meta.path,
+            Meta::Verbatim(ref meta) => &meta.path,
         }
     }
+
+    /// Returns the identifier that begins this structured meta item, for the
+    /// common case where [`path`](#method.path) is a single segment.
+    ///
+    /// For example this would return the `test` in `#[test]`, the `derive`
+    /// in `#[derive(Copy)]`, and the `path` in `#[path = "sys/windows.rs"]`.
+    /// For a multi-segment path like `#[crate::precondition]` this returns
+    /// just the last segment, `precondition`; use [`path`](#method.path) to
+    /// get the whole thing.
+    pub fn name(&self) -> Ident {
+        self.path().segments.last().unwrap().value().ident
+    }
 }
 
 ast_enum_of_structs! {
@@ -330,6 +502,16 @@ pub trait FilterAttrs<'a> {
 
     fn outer(self) -> Self::Ret;
     fn inner(self) -> Self::Ret;
+
+    /// Returns the concatenated text of every doc comment attribute in this
+    /// iterator, recognizing both sugared comments (`///`, `//!`, `/** */`,
+    /// `/*! */`) and the explicit `#[doc = "..."]` form they desugar to, in
+    /// the order the attributes appear.
+    ///
+    /// Returns `None` if none of the attributes are doc comments.
+    fn doc_text(self) -> Option<String>
+    where
+        Self: Sized;
 }
 
 impl<'a, T> FilterAttrs<'a> for T
@@ -357,6 +539,37 @@ where
         }
         self.into_iter().filter(is_inner)
     }
+
+    fn doc_text(self) -> Option<String> {
+        let mut lines = self
+            .into_iter()
+            .filter_map(|attr| {
+                if !path_is_ident(&attr.path, "doc") {
+                    return None;
+                }
+                match attr.interpret_meta() {
+                    Some(Meta::NameValue(meta)) => match meta.lit {
+                        Lit::Str(s) => Some(s.value()),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            })
+            .peekable();
+
+        if lines.peek().is_none() {
+            return None;
+        }
+
+        let mut text = String::new();
+        for line in lines {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&line);
+        }
+        Some(text)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -364,8 +577,8 @@ pub mod parsing {
     use super::*;
     use buffer::Cursor;
     use parse_error;
-    use synom::PResult;
-    use proc_macro2::{Literal, Spacing, Span, TokenNode, TokenTree};
+    use synom::{ParseError, PResult, Synom};
+    use proc_macro2::{Delimiter, Literal, Spacing, Span, TokenNode, TokenTree};
 
     fn eq(span: Span) -> TokenTree {
         TokenTree {
@@ -375,6 +588,34 @@ pub mod parsing {
     }
 
     impl Attribute {
+        /// Parses the arguments to the attribute as the syntax tree type `T`.
+        ///
+        /// The surrounding delimiter, if the body is written as a
+        /// parenthesized, bracketed, or braced group (as in
+        /// `#[derive(Copy)]`), is discarded automatically so that `T` only
+        /// ever sees the tokens inside it. Attributes whose body is not
+        /// delimited at all, like the hypothetical `#[precondition x < 5]`,
+        /// are parsed as-is.
+        ///
+        /// This is the escape hatch for attributes whose grammar does not fit
+        /// the word/list/name-value shape that [`interpret_meta`] recognizes.
+        ///
+        /// [`interpret_meta`]: #method.interpret_meta
+        pub fn parse_args<T: Synom>(&self) -> Result<T, ParseError> {
+            let tts = self.tts.clone().into_iter().collect::<Vec<_>>();
+            let body = if tts.len() == 1 {
+                match tts[0].kind {
+                    TokenNode::Group(Delimiter::Parenthesis, ref inner)
+                    | TokenNode::Group(Delimiter::Bracket, ref inner)
+                    | TokenNode::Group(Delimiter::Brace, ref inner) => inner.clone(),
+                    _ => self.tts.clone(),
+                }
+            } else {
+                self.tts.clone()
+            };
+            synom::parse2(body)
+        }
+
         named!(pub parse_inner -> Self, alt!(
             do_parse!(
                 pound: punct!(#) >>
@@ -498,7 +739,7 @@ mod printing {
             // If this was a sugared doc, emit it in its original form instead of `#[doc = "..."]`
             if self.is_sugared_doc {
                 if let Some(Meta::NameValue(ref pair)) = self.interpret_meta() {
-                    if pair.ident == "doc" {
+                    if path_is_ident(&pair.path, "doc") {
                         if let Lit::Str(ref comment) = pair.lit {
                             tokens.append(TokenTree {
                                 span: comment.span,
@@ -523,18 +764,237 @@ mod printing {
 
     impl ToTokens for MetaList {
         fn to_tokens(&self, tokens: &mut Tokens) {
-            self.ident.to_tokens(tokens);
-            self.paren_token.surround(tokens, |tokens| {
-                self.nested.to_tokens(tokens);
-            })
+            self.path.to_tokens(tokens);
+            match self.delimiter {
+                MetaListDelim::Paren(ref paren) => {
+                    paren.surround(tokens, |tokens| self.nested.to_tokens(tokens))
+                }
+                MetaListDelim::Bracket(ref bracket) => {
+                    bracket.surround(tokens, |tokens| self.nested.to_tokens(tokens))
+                }
+                MetaListDelim::Brace(ref brace) => {
+                    brace.surround(tokens, |tokens| self.nested.to_tokens(tokens))
+                }
+            }
         }
     }
 
     impl ToTokens for MetaNameValue {
         fn to_tokens(&self, tokens: &mut Tokens) {
-            self.ident.to_tokens(tokens);
+            self.path.to_tokens(tokens);
             self.eq_token.to_tokens(tokens);
             self.lit.to_tokens(tokens);
         }
     }
+
+    impl ToTokens for MetaVerbatim {
+        fn to_tokens(&self, tokens: &mut Tokens) {
+            self.path.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.tts.to_tokens(tokens);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::{Literal, Span};
+
+    fn outer_attr(source: &str) -> Attribute {
+        let tokens: TokenStream = source.parse().expect("failed to tokenize attribute");
+        let buffer = buffer::TokenBuffer::new2(tokens);
+        let (attr, rest) =
+            Attribute::parse_outer(buffer.begin()).expect("failed to parse attribute");
+        assert!(rest.eof());
+        attr
+    }
+
+    // Built by hand in the shape `parsing::lit_doc_comment` gives a sugared
+    // doc comment: an `=` followed by a string literal, with
+    // `is_sugared_doc` set. `lit_doc_comment` keeps the comment markers
+    // (`///` and so on) in that string; here the string is exactly `text`.
+    fn sugared_doc_attr(text: &str) -> Attribute {
+        let span = Span::call_site();
+        Attribute {
+            pound_token: <Token![#]>::new(span),
+            style: AttrStyle::Outer,
+            bracket_token: token::Bracket(span),
+            path: Ident::new("doc", span).into(),
+            tts: vec![
+                TokenTree {
+                    span: span,
+                    kind: TokenNode::Op('=', Spacing::Alone),
+                },
+                TokenTree {
+                    span: span,
+                    kind: TokenNode::Literal(Literal::string(text)),
+                },
+            ].into_iter()
+                .collect(),
+            is_sugared_doc: true,
+        }
+    }
+
+    #[test]
+    fn interpret_meta_multi_segment_word() {
+        match outer_attr("#[crate::precondition]").interpret_meta() {
+            Some(Meta::Word(path)) => {
+                let idents: Vec<String> =
+                    path.segments.iter().map(|s| s.value().ident.to_string()).collect();
+                assert_eq!(idents, vec!["crate", "precondition"]);
+            }
+            _ => panic!("expected a multi-segment Meta::Word"),
+        }
+    }
+
+    #[test]
+    fn interpret_meta_multi_segment_word_nested() {
+        match outer_attr("#[cfg_attr(foo, a::b)]").interpret_meta() {
+            Some(Meta::List(list)) => {
+                let nested: Vec<&NestedMeta> = list.nested.iter().collect();
+                assert_eq!(nested.len(), 2);
+                match nested[0] {
+                    NestedMeta::Meta(Meta::Word(ref path)) => {
+                        assert!(path_is_ident(path, "foo"))
+                    }
+                    _ => panic!("expected a `foo` word"),
+                }
+                match nested[1] {
+                    NestedMeta::Meta(Meta::Word(ref path)) => {
+                        let idents: Vec<String> = path.segments
+                            .iter()
+                            .map(|s| s.value().ident.to_string())
+                            .collect();
+                        assert_eq!(idents, vec!["a", "b"]);
+                    }
+                    _ => panic!("expected a multi-segment `a::b` word"),
+                }
+            }
+            _ => panic!("expected a Meta::List"),
+        }
+    }
+
+    #[test]
+    fn parse_args_unwraps_parens() {
+        let lit: Lit = outer_attr("#[foo(5)]").parse_args().unwrap();
+        match lit {
+            Lit::Int(int) => assert_eq!(int.value(), 5),
+            _ => panic!("expected an integer literal"),
+        }
+    }
+
+    #[test]
+    fn parse_args_unwraps_brackets_and_braces() {
+        for source in &["#[foo[5]]", "#[foo{5}]"] {
+            let lit: Lit = outer_attr(source).parse_args().unwrap();
+            match lit {
+                Lit::Int(int) => assert_eq!(int.value(), 5),
+                _ => panic!("expected an integer literal"),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_args_accepts_bare_tokens() {
+        let lit: Lit = outer_attr("#[foo 5]").parse_args().unwrap();
+        match lit {
+            Lit::Int(int) => assert_eq!(int.value(), 5),
+            _ => panic!("expected an integer literal"),
+        }
+    }
+
+    #[test]
+    fn doc_text_joins_sugared_comments_in_order() {
+        let attrs = vec![sugared_doc_attr(" one"), sugared_doc_attr(" two")];
+        assert_eq!(attrs.iter().doc_text(), Some(" one\n two".to_string()));
+    }
+
+    #[test]
+    fn doc_text_recognizes_explicit_doc_attr() {
+        let attrs = vec![outer_attr("#[doc = \"hello\"]")];
+        assert_eq!(attrs.iter().doc_text(), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn doc_text_mixes_sugared_and_explicit_forms() {
+        let attrs = vec![sugared_doc_attr(" one"), outer_attr("#[doc = \"two\"]")];
+        assert_eq!(attrs.iter().doc_text(), Some(" one\ntwo".to_string()));
+    }
+
+    #[test]
+    fn doc_text_none_without_doc_attrs() {
+        let attrs = vec![outer_attr("#[test]"), outer_attr("#[derive(Copy)]")];
+        assert_eq!(attrs.iter().doc_text(), None);
+    }
+
+    #[test]
+    fn interpret_meta_bracket_list_delim() {
+        match outer_attr("#[foo[1, 2]]").interpret_meta() {
+            Some(Meta::List(list)) => {
+                assert_eq!(list.nested.len(), 2);
+                match list.delimiter {
+                    MetaListDelim::Bracket(_) => {}
+                    _ => panic!("expected a bracket-delimited list"),
+                }
+            }
+            _ => panic!("expected a Meta::List"),
+        }
+    }
+
+    #[test]
+    fn interpret_meta_brace_list_delim() {
+        match outer_attr("#[foo{1, 2}]").interpret_meta() {
+            Some(Meta::List(list)) => {
+                assert_eq!(list.nested.len(), 2);
+                match list.delimiter {
+                    MetaListDelim::Brace(_) => {}
+                    _ => panic!("expected a brace-delimited list"),
+                }
+            }
+            _ => panic!("expected a Meta::List"),
+        }
+    }
+
+    #[test]
+    fn interpret_meta_nested_verbatim_rhs() {
+        match outer_attr("#[cfg_attr(foo, precondition = x < 5)]").interpret_meta() {
+            Some(Meta::List(list)) => {
+                let nested: Vec<&NestedMeta> = list.nested.iter().collect();
+                assert_eq!(nested.len(), 2);
+                match nested[1] {
+                    NestedMeta::Meta(Meta::Verbatim(ref verbatim)) => {
+                        assert!(path_is_ident(&verbatim.path, "precondition"));
+                        assert_eq!(verbatim.tts.to_string(), "x < 5");
+                    }
+                    _ => panic!("expected a verbatim nested meta item"),
+                }
+            }
+            _ => panic!("expected a Meta::List"),
+        }
+    }
+
+    #[test]
+    fn nested_meta_item_from_tokens_verbatim_rhs_cursor() {
+        let tokens: TokenStream = "precondition = x < 5, bar".parse().unwrap();
+        let tts: Vec<TokenTree> = tokens.into_iter().collect();
+        let (item, rest) =
+            nested_meta_item_from_tokens(&tts).expect("failed to parse nested meta item");
+
+        match item {
+            NestedMeta::Meta(Meta::Verbatim(ref verbatim)) => {
+                assert!(path_is_ident(&verbatim.path, "precondition"));
+                assert_eq!(verbatim.tts.to_string(), "x < 5");
+            }
+            _ => panic!("expected a verbatim nested meta item"),
+        }
+
+        // `rest` should stop right at the comma separating this item from
+        // the next, not swallow it or stop short inside the `x < 5`.
+        assert_eq!(rest.len(), 2);
+        match rest[0].kind {
+            TokenNode::Op(',', _) => {}
+            _ => panic!("expected rest to start at the trailing comma"),
+        }
+    }
 }